@@ -31,8 +31,8 @@ pub fn init_command() -> Command {
                 .short('p')
                 .long("pattern")
                 .value_name("PATTERN")
-                .help("The regex pattern to match")
-                .action(clap::ArgAction::Set)
+                .help("The regex pattern to match. May be given multiple times to match against several patterns in a single pass")
+                .action(clap::ArgAction::Append)
                 .required(true),
         )
         .arg(
@@ -40,8 +40,8 @@ pub fn init_command() -> Command {
                 .short('r')
                 .long("replacement")
                 .value_name("REPLACEMENT")
-                .help("The string to replace matches with")
-                .action(clap::ArgAction::Set)
+                .help("The string to replace matches with. Given once per --pattern, in the same order")
+                .action(clap::ArgAction::Append)
                 .required(true),
         )
         .arg(
@@ -49,8 +49,31 @@ pub fn init_command() -> Command {
                 .long("haystack")
                 .value_name("HAYSTACK")
                 .help("The string to search within")
+                .conflicts_with_all(["stdin", "file"])
                 .action(clap::ArgAction::Set),
         )
+        .arg(
+            Arg::new("stdin")
+                .long("stdin")
+                .help("Read the haystack from standard input")
+                .conflicts_with_all(["haystack", "file"])
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("file")
+                .long("file")
+                .value_name("PATH")
+                .help("Read the haystack from a file, streaming it line by line")
+                .conflicts_with_all(["haystack", "stdin"])
+                .action(clap::ArgAction::Set),
+        )
+        .arg(
+            Arg::new("in_place")
+                .long("in-place")
+                .help("Rewrite the --file in place instead of printing the result")
+                .requires("file")
+                .action(clap::ArgAction::SetTrue),
+        )
         .arg(
             Arg::new("all")
                 .long("all")
@@ -73,22 +96,158 @@ pub fn init_command() -> Command {
                 .value_parser(clap::value_parser!(u16).range(0..))
                 .action(clap::ArgAction::Set),
         )
+        .arg(
+            Arg::new("bytes")
+                .long("bytes")
+                .help("Operate on raw bytes instead of UTF-8 text, for binary-safe replacement")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("ignore_case")
+                .short('i')
+                .long("ignore-case")
+                .help("Make the pattern case-insensitive")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("multiline")
+                .long("multiline")
+                .help("Make ^ and $ match the start and end of each line")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("dot_matches_newline")
+                .long("dot-matches-newline")
+                .help("Make . match newlines as well")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("ignore_whitespace")
+                .long("ignore-whitespace")
+                .help("Ignore whitespace and allow # comments in the pattern")
+                .action(clap::ArgAction::SetTrue),
+        )
+}
+
+/// The inline regex flags that may be toggled from the command line,
+/// mirroring the options exposed by `regex::RegexBuilder`.
+///
+/// # Examples
+///
+/// ```
+/// use replace::RegexFlags;
+/// let flags = RegexFlags { ignore_case: true, ..RegexFlags::default() };
+/// assert!(flags.ignore_case);
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RegexFlags {
+    /// Corresponds to `--ignore-case` / the `i` flag.
+    pub ignore_case: bool,
+    /// Corresponds to `--multiline` / the `m` flag.
+    pub multiline: bool,
+    /// Corresponds to `--dot-matches-newline` / the `s` flag.
+    pub dot_matches_newline: bool,
+    /// Corresponds to `--ignore-whitespace` / the `x` flag.
+    pub ignore_whitespace: bool,
+}
+
+/// Builds a `Regex` from the given pattern, applying the requested inline
+/// flags. This is the shared builder used by both [`verify_is_valid_regex`]
+/// and [`find_matches`] so validation and matching stay consistent.
+///
+/// # Arguments
+///
+/// * `pattern` - A regex pattern to compile.
+/// * `flags` - The inline flags to apply before compilation.
+///
+/// # Returns
+///
+/// A `Result` containing the compiled `Regex`, or the underlying
+/// `regex::Error` if the pattern is invalid.
+fn build_regex(pattern: &str, flags: RegexFlags) -> Result<Regex, regex::Error> {
+    regex::RegexBuilder::new(pattern)
+        .case_insensitive(flags.ignore_case)
+        .multi_line(flags.multiline)
+        .dot_matches_new_line(flags.dot_matches_newline)
+        .ignore_whitespace(flags.ignore_whitespace)
+        .build()
+}
+
+/// Builds a byte-oriented `regex::bytes::Regex` from the given pattern,
+/// applying the requested inline flags. The byte-mode counterpart of
+/// [`build_regex`], used by [`replace_matches_bytes`] so `--bytes` mode
+/// respects the same `--ignore-case`/`--multiline`/`--dot-matches-newline`/
+/// `--ignore-whitespace` flags as the `&str` path.
+///
+/// # Arguments
+///
+/// * `pattern` - A regex pattern to compile.
+/// * `flags` - The inline flags to apply before compilation.
+///
+/// # Returns
+///
+/// A `Result` containing the compiled byte-mode `Regex`, or the underlying
+/// `regex::Error` if the pattern is invalid.
+fn build_regex_bytes(pattern: &str, flags: RegexFlags) -> Result<regex::bytes::Regex, regex::Error> {
+    regex::bytes::RegexBuilder::new(pattern)
+        .case_insensitive(flags.ignore_case)
+        .multi_line(flags.multiline)
+        .dot_matches_new_line(flags.dot_matches_newline)
+        .ignore_whitespace(flags.ignore_whitespace)
+        .build()
+}
+
+/// Decides whether the match at 1-based position `count` should be replaced,
+/// under the `--all`/`--nth`/`--every_nth` selection flags. Shared by every
+/// replacement function (`&str`, `&[u8]`, multi-pattern, and per-line) so the
+/// selection logic stays in one place. `count` is a `usize` rather than the
+/// `u16` that `--nth`/`--every_nth` are parsed as, since the number of
+/// matches in the haystack is unbounded and must not overflow.
+fn is_match_selected(count: usize, all: bool, nth: Option<u16>, every_nth: Option<u16>) -> bool {
+    all || nth.is_some_and(|n| count == n as usize)
+        || every_nth.is_some_and(|n| n != 0 && count.is_multiple_of(n as usize))
+}
+
+/// Validates that the given pattern is a valid regular expression, under the
+/// given inline flags.
+///
+/// # Arguments
+///
+/// * `pattern` - A string slice that holds the regex pattern.
+/// * `flags` - The inline flags to apply before compilation.
+///
+/// # Examples
+///
+/// ```
+/// use replace::{verify_is_valid_regex, RegexFlags};
+/// verify_is_valid_regex(r"\d+", RegexFlags::default());
+/// ```
+pub fn verify_is_valid_regex(pattern: &str, flags: RegexFlags) {
+    if build_regex(pattern, flags).is_err() {
+        eprintln!(
+            "Error: The pattern given is not a valid regular expression: {}",
+            pattern
+        );
+        std::process::exit(1);
+    }
 }
 
-/// Validates that the given pattern is a valid regular expression.
+/// Validates that the given pattern is a valid byte-oriented regular
+/// expression, under the given inline flags, for use with `--bytes` mode.
 ///
 /// # Arguments
 ///
 /// * `pattern` - A string slice that holds the regex pattern.
+/// * `flags` - The inline flags to apply before compilation.
 ///
 /// # Examples
 ///
 /// ```
-/// use replace::verify_is_valid_regex;
-/// verify_is_valid_regex(r"\d+");
+/// use replace::{verify_is_valid_regex_bytes, RegexFlags};
+/// verify_is_valid_regex_bytes(r"\d+", RegexFlags::default());
 /// ```
-pub fn verify_is_valid_regex(pattern: &str) {
-    if Regex::new(pattern).is_err() {
+pub fn verify_is_valid_regex_bytes(pattern: &str, flags: RegexFlags) {
+    if build_regex_bytes(pattern, flags).is_err() {
         eprintln!(
             "Error: The pattern given is not a valid regular expression: {}",
             pattern
@@ -168,12 +327,44 @@ pub fn verify_at_least_one_option_is_provided(
     }
 }
 
-/// Finds all matches of the given pattern in the content string.
+/// Checks whether data is being piped into standard input, i.e. stdin is not
+/// an interactive terminal.
+///
+/// # Returns
+///
+/// `true` if stdin is piped, `false` if it is an interactive terminal.
+pub fn is_stdin_piped() -> bool {
+    use std::io::IsTerminal;
+    !std::io::stdin().is_terminal()
+}
+
+/// Reads all of standard input into a string.
+///
+/// # Returns
+///
+/// The full contents of standard input as a `String`.
+///
+/// # Panics
+///
+/// This function will panic if standard input cannot be read, for example if
+/// it does not contain valid UTF-8.
+pub fn read_stdin() -> String {
+    use std::io::Read;
+    let mut buffer = String::new();
+    std::io::stdin()
+        .read_to_string(&mut buffer)
+        .expect("Failed to read from stdin");
+    buffer
+}
+
+/// Finds all matches of the given pattern in the content string, under the
+/// given inline flags.
 ///
 /// # Arguments
 ///
 /// * `pattern` - A regex pattern to match.
 /// * `content` - The string to search within.
+/// * `flags` - The inline flags to apply before compilation.
 ///
 /// # Returns
 ///
@@ -182,13 +373,654 @@ pub fn verify_at_least_one_option_is_provided(
 /// # Examples
 ///
 /// ```
-/// use replace::find_matches;
-/// let matches = find_matches(r"\d+", "123 abc 456");
+/// use replace::{find_matches, RegexFlags};
+/// let matches = find_matches(r"\d+", "123 abc 456", RegexFlags::default());
+/// assert_eq!(matches, vec![(0, 3), (8, 11)]);
+///
+/// let flags = RegexFlags { ignore_case: true, ..RegexFlags::default() };
+/// let matches = find_matches(r"abc", "ABC def", flags);
+/// assert_eq!(matches, vec![(0, 3)]);
+/// ```
+pub fn find_matches(pattern: &str, content: &str, flags: RegexFlags) -> Vec<(usize, usize)> {
+    let re = build_regex(pattern, flags).expect(&format!("Invalid regex pattern: {}", pattern));
+    re.find_iter(content)
+        .map(|found_match| (found_match.start(), found_match.end()))
+        .collect()
+}
+
+/// Finds all matches of the given pattern in a byte slice, under the given
+/// inline flags, for use with `--bytes` mode. Operates on `&[u8]` so input
+/// that is not valid UTF-8 (binary files, latin-1 logs) can be processed
+/// without lossy conversion.
+///
+/// # Arguments
+///
+/// * `pattern` - A regex pattern to match.
+/// * `content` - The byte slice to search within.
+/// * `flags` - The inline flags to apply before compilation.
+///
+/// # Returns
+///
+/// A vector of tuples where each tuple contains the start and end indices of
+/// a match.
+///
+/// # Examples
+///
+/// ```
+/// use replace::{find_matches_bytes, RegexFlags};
+/// let matches = find_matches_bytes(r"\d+", b"123 abc 456", RegexFlags::default());
 /// assert_eq!(matches, vec![(0, 3), (8, 11)]);
 /// ```
-pub fn find_matches(pattern: &str, content: &str) -> Vec<(usize, usize)> {
-    let re = Regex::new(pattern).expect(&format!("Invalid regex pattern: {}", pattern));
+pub fn find_matches_bytes(pattern: &str, content: &[u8], flags: RegexFlags) -> Vec<(usize, usize)> {
+    let re = build_regex_bytes(pattern, flags)
+        .expect(&format!("Invalid regex pattern: {}", pattern));
     re.find_iter(content)
         .map(|found_match| (found_match.start(), found_match.end()))
         .collect()
 }
+
+/// Expands capture group references in a replacement byte string using the
+/// given byte `Captures`, following the same syntax as [`expand_replacement`]:
+/// `$1`/`$2` for numbered groups, `${name}` for named groups, and `$$` for a
+/// literal dollar sign.
+///
+/// # Arguments
+///
+/// * `replacement` - The replacement bytes, which may contain capture group
+///   references.
+/// * `captures` - The byte `Captures` produced by matching a pattern against
+///   the haystack.
+///
+/// # Returns
+///
+/// The replacement bytes with all capture group references substituted with
+/// their matched bytes (or nothing if the group did not participate).
+///
+/// # Examples
+///
+/// ```
+/// use regex::bytes::Regex;
+/// use replace::expand_replacement_bytes;
+///
+/// let re = Regex::new(r"(?P<year>\d{4})-(\d{2})").unwrap();
+/// let captures = re.captures(b"2024-03").unwrap();
+/// assert_eq!(expand_replacement_bytes(b"$2/${year}", &captures), b"03/2024");
+/// ```
+pub fn expand_replacement_bytes(replacement: &[u8], captures: &regex::bytes::Captures) -> Vec<u8> {
+    let mut expanded = Vec::with_capacity(replacement.len());
+    let mut i = 0;
+
+    while i < replacement.len() {
+        if replacement[i] != b'$' {
+            expanded.push(replacement[i]);
+            i += 1;
+            continue;
+        }
+
+        if i + 1 < replacement.len() && replacement[i + 1] == b'$' {
+            expanded.push(b'$');
+            i += 2;
+            continue;
+        }
+
+        if i + 1 < replacement.len() && replacement[i + 1] == b'{' {
+            if let Some(len) = replacement[i + 2..].iter().position(|&b| b == b'}') {
+                let name = std::str::from_utf8(&replacement[i + 2..i + 2 + len])
+                    .expect("capture group name must be valid UTF-8");
+                match name.parse::<usize>() {
+                    Ok(index) => expanded
+                        .extend_from_slice(captures.get(index).map_or(&b""[..], |m| m.as_bytes())),
+                    Err(_) => expanded
+                        .extend_from_slice(captures.name(name).map_or(&b""[..], |m| m.as_bytes())),
+                }
+                i += 2 + len + 1;
+                continue;
+            }
+        }
+
+        let name_start = i + 1;
+        let mut name_end = name_start;
+        while name_end < replacement.len()
+            && (replacement[name_end].is_ascii_alphanumeric() || replacement[name_end] == b'_')
+        {
+            name_end += 1;
+        }
+
+        if name_end == name_start {
+            expanded.push(b'$');
+            i += 1;
+            continue;
+        }
+
+        let name = std::str::from_utf8(&replacement[name_start..name_end])
+            .expect("capture group name must be valid UTF-8");
+        match name.parse::<usize>() {
+            Ok(index) => {
+                expanded.extend_from_slice(captures.get(index).map_or(&b""[..], |m| m.as_bytes()))
+            }
+            Err(_) => {
+                expanded.extend_from_slice(captures.name(name).map_or(&b""[..], |m| m.as_bytes()))
+            }
+        }
+        i = name_end;
+    }
+
+    expanded
+}
+
+/// Replaces the selected matches of a regex pattern in the given byte slice,
+/// expanding capture group references in the replacement. This is the
+/// byte-oriented counterpart of [`replace_matches`], for use with `--bytes`
+/// mode so the selection logic (`--all`/`--nth`/`--every_nth`) stays shared
+/// between the two modes.
+///
+/// # Arguments
+///
+/// * `pattern` - A regex pattern to match.
+/// * `content` - The byte slice to search within.
+/// * `replacement` - The replacement bytes, which may contain capture group
+///   references (`$1`, `${name}`, `$$`).
+/// * `all` - Replace every match when `true`.
+/// * `nth` - Replace only the 1-based nth match, if given.
+/// * `every_nth` - Replace every 1-based nth match, if given.
+/// * `flags` - The inline flags to apply before compilation.
+///
+/// # Returns
+///
+/// The content with the selected matches replaced.
+///
+/// # Examples
+///
+/// ```
+/// use replace::{replace_matches_bytes, RegexFlags};
+///
+/// let result = replace_matches_bytes(r"\d+", b"a1 b2", b"#", true, None, None, RegexFlags::default());
+/// assert_eq!(result, b"a# b#");
+/// ```
+pub fn replace_matches_bytes(
+    pattern: &str,
+    content: &[u8],
+    replacement: &[u8],
+    all: bool,
+    nth: Option<u16>,
+    every_nth: Option<u16>,
+    flags: RegexFlags,
+) -> Vec<u8> {
+    let re = build_regex_bytes(pattern, flags)
+        .expect(&format!("Invalid regex pattern: {}", pattern));
+    let mut result = Vec::with_capacity(content.len());
+    let mut last_end = 0;
+
+    for (index, captures) in re.captures_iter(content).enumerate() {
+        let found_match = captures.get(0).unwrap();
+        let count = index + 1;
+
+        result.extend_from_slice(&content[last_end..found_match.start()]);
+        if is_match_selected(count, all, nth, every_nth) {
+            result.extend_from_slice(&expand_replacement_bytes(replacement, &captures));
+        } else {
+            result.extend_from_slice(found_match.as_bytes());
+        }
+        last_end = found_match.end();
+    }
+
+    result.extend_from_slice(&content[last_end..]);
+    result
+}
+
+/// Resolves overlapping candidate matches by leftmost-longest: the earliest
+/// start index wins, ties broken by the longest end index. Each output
+/// region is covered by at most one match.
+fn resolve_leftmost_longest(
+    mut candidates: Vec<(usize, usize, usize)>,
+) -> Vec<(usize, usize, usize)> {
+    candidates.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+
+    let mut resolved = Vec::new();
+    let mut last_end = 0;
+
+    for (start, end, pattern_index) in candidates {
+        if start < last_end {
+            continue;
+        }
+
+        resolved.push((start, end, pattern_index));
+        last_end = end;
+    }
+
+    resolved
+}
+
+/// Finds all matches across multiple patterns in the content string using a
+/// `RegexSet`, resolving overlapping candidate spans by leftmost-longest (see
+/// [`resolve_leftmost_longest`]).
+///
+/// # Arguments
+///
+/// * `patterns` - The regex patterns to match, in declaration order.
+/// * `content` - The string to search within.
+/// * `flags` - The inline flags to apply before compilation.
+///
+/// # Returns
+///
+/// A vector of `(start, end, pattern_index)` tuples, sorted by position,
+/// where `pattern_index` is the index into `patterns` of the pattern that
+/// produced the match.
+///
+/// # Examples
+///
+/// ```
+/// use replace::{find_matches_multi, RegexFlags};
+/// let matches = find_matches_multi(&[r"\d+", r"[a-z]+"], "123 abc 456", RegexFlags::default());
+/// assert_eq!(matches, vec![(0, 3, 0), (4, 7, 1), (8, 11, 0)]);
+/// ```
+pub fn find_matches_multi(
+    patterns: &[&str],
+    content: &str,
+    flags: RegexFlags,
+) -> Vec<(usize, usize, usize)> {
+    let set = regex::RegexSetBuilder::new(patterns)
+        .case_insensitive(flags.ignore_case)
+        .multi_line(flags.multiline)
+        .dot_matches_new_line(flags.dot_matches_newline)
+        .ignore_whitespace(flags.ignore_whitespace)
+        .build()
+        .expect("Invalid regex pattern in set");
+    let matching_indices: Vec<usize> = set.matches(content).iter().collect();
+
+    let mut candidates: Vec<(usize, usize, usize)> = Vec::new();
+    for pattern_index in matching_indices {
+        let re = build_regex(patterns[pattern_index], flags)
+            .expect(&format!("Invalid regex pattern: {}", patterns[pattern_index]));
+        for found_match in re.find_iter(content) {
+            candidates.push((found_match.start(), found_match.end(), pattern_index));
+        }
+    }
+
+    resolve_leftmost_longest(candidates)
+}
+
+/// Replaces the selected matches of multiple regex patterns in the given
+/// content in a single pass, expanding capture group references in each
+/// pattern's own replacement string. Overlapping matches are resolved by
+/// leftmost-longest (see [`resolve_leftmost_longest`]), and the `--all`/
+/// `--nth`/`--every_nth` selection counters operate over the merged,
+/// position-sorted match stream.
+///
+/// # Arguments
+///
+/// * `patterns` - The regex patterns to match, in declaration order.
+/// * `replacements` - The replacement string for each pattern, by index.
+/// * `content` - The string to search within.
+/// * `all` - Replace every match when `true`.
+/// * `nth` - Replace only the 1-based nth match, if given.
+/// * `every_nth` - Replace every 1-based nth match, if given.
+/// * `flags` - The inline flags to apply before compilation.
+///
+/// # Returns
+///
+/// The content with the selected matches replaced.
+///
+/// # Examples
+///
+/// ```
+/// use replace::{replace_matches_multi, RegexFlags};
+///
+/// let result = replace_matches_multi(&[r"\d+", r"[a-z]+"], &["#", "_"], "123 abc 456", true, None, None, RegexFlags::default());
+/// assert_eq!(result, "# _ #");
+/// ```
+pub fn replace_matches_multi(
+    patterns: &[&str],
+    replacements: &[&str],
+    content: &str,
+    all: bool,
+    nth: Option<u16>,
+    every_nth: Option<u16>,
+    flags: RegexFlags,
+) -> String {
+    let regexes: Vec<Regex> = patterns
+        .iter()
+        .map(|pattern| build_regex(pattern, flags).expect(&format!("Invalid regex pattern: {}", pattern)))
+        .collect();
+
+    let set = regex::RegexSetBuilder::new(patterns)
+        .case_insensitive(flags.ignore_case)
+        .multi_line(flags.multiline)
+        .dot_matches_new_line(flags.dot_matches_newline)
+        .ignore_whitespace(flags.ignore_whitespace)
+        .build()
+        .expect("Invalid regex pattern in set");
+    let mut candidates: Vec<(usize, usize, usize)> = Vec::new();
+    for pattern_index in set.matches(content).iter() {
+        for found_match in regexes[pattern_index].find_iter(content) {
+            candidates.push((found_match.start(), found_match.end(), pattern_index));
+        }
+    }
+
+    let resolved = resolve_leftmost_longest(candidates);
+
+    let mut result = String::with_capacity(content.len());
+    let mut last_end = 0;
+
+    for (index, (start, end, pattern_index)) in resolved.into_iter().enumerate() {
+        let count = index + 1;
+
+        result.push_str(&content[last_end..start]);
+        if is_match_selected(count, all, nth, every_nth) {
+            let captures = regexes[pattern_index]
+                .captures_at(content, start)
+                .expect("match disappeared during replacement");
+            result.push_str(&expand_replacement(replacements[pattern_index], &captures));
+        } else {
+            result.push_str(&content[start..end]);
+        }
+        last_end = end;
+    }
+
+    result.push_str(&content[last_end..]);
+    result
+}
+
+/// Expands capture group references in a replacement string using the given
+/// `Captures`, following the `regex` crate's replacement syntax: `$1`/`$2` for
+/// numbered groups, `${name}` for named groups, and `$$` for a literal dollar
+/// sign. A `$` not followed by a valid reference is emitted as-is.
+///
+/// # Arguments
+///
+/// * `replacement` - The replacement string, which may contain capture group
+///   references.
+/// * `captures` - The `Captures` produced by matching a pattern against the
+///   haystack.
+///
+/// # Returns
+///
+/// The replacement string with all capture group references substituted with
+/// their matched text (or an empty string if the group did not participate).
+///
+/// # Examples
+///
+/// ```
+/// use regex::Regex;
+/// use replace::expand_replacement;
+///
+/// let re = Regex::new(r"(?P<year>\d{4})-(\d{2})").unwrap();
+/// let captures = re.captures("2024-03").unwrap();
+/// assert_eq!(expand_replacement("$2/${year}", &captures), "03/2024");
+/// ```
+pub fn expand_replacement(replacement: &str, captures: &regex::Captures) -> String {
+    let mut expanded = String::with_capacity(replacement.len());
+    let bytes = replacement.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] != b'$' {
+            let ch = replacement[i..].chars().next().unwrap();
+            expanded.push(ch);
+            i += ch.len_utf8();
+            continue;
+        }
+
+        if i + 1 < bytes.len() && bytes[i + 1] == b'$' {
+            expanded.push('$');
+            i += 2;
+            continue;
+        }
+
+        if i + 1 < bytes.len() && bytes[i + 1] == b'{' {
+            if let Some(len) = replacement[i + 2..].find('}') {
+                let name = &replacement[i + 2..i + 2 + len];
+                match name.parse::<usize>() {
+                    Ok(index) => expanded.push_str(captures.get(index).map_or("", |m| m.as_str())),
+                    Err(_) => expanded.push_str(captures.name(name).map_or("", |m| m.as_str())),
+                }
+                i += 2 + len + 1;
+                continue;
+            }
+        }
+
+        let name_start = i + 1;
+        let mut name_end = name_start;
+        while name_end < bytes.len()
+            && (bytes[name_end].is_ascii_alphanumeric() || bytes[name_end] == b'_')
+        {
+            name_end += 1;
+        }
+
+        if name_end == name_start {
+            expanded.push('$');
+            i += 1;
+            continue;
+        }
+
+        let name = &replacement[name_start..name_end];
+        match name.parse::<usize>() {
+            Ok(index) => expanded.push_str(captures.get(index).map_or("", |m| m.as_str())),
+            Err(_) => expanded.push_str(captures.name(name).map_or("", |m| m.as_str())),
+        }
+        i = name_end;
+    }
+
+    expanded
+}
+
+/// Replaces the selected matches of a regex pattern in the given content,
+/// expanding capture group references in the replacement string.
+///
+/// # Arguments
+///
+/// * `pattern` - A regex pattern to match.
+/// * `content` - The string to search within.
+/// * `replacement` - The replacement string, which may contain capture group
+///   references (`$1`, `${name}`, `$$`).
+/// * `all` - Replace every match when `true`.
+/// * `nth` - Replace only the 1-based nth match, if given.
+/// * `every_nth` - Replace every 1-based nth match, if given.
+/// * `flags` - The inline flags to apply before compilation.
+///
+/// # Returns
+///
+/// The content with the selected matches replaced.
+///
+/// # Examples
+///
+/// ```
+/// use replace::{replace_matches, RegexFlags};
+///
+/// let result = replace_matches(r"(\w)(\w+)", "hello world", "$2$1", true, None, None, RegexFlags::default());
+/// assert_eq!(result, "elloh orldw");
+/// ```
+pub fn replace_matches(
+    pattern: &str,
+    content: &str,
+    replacement: &str,
+    all: bool,
+    nth: Option<u16>,
+    every_nth: Option<u16>,
+    flags: RegexFlags,
+) -> String {
+    let re = build_regex(pattern, flags).expect(&format!("Invalid regex pattern: {}", pattern));
+    let mut result = String::with_capacity(content.len());
+    let mut last_end = 0;
+
+    for (index, captures) in re.captures_iter(content).enumerate() {
+        let found_match = captures.get(0).unwrap();
+        let count = index + 1;
+
+        result.push_str(&content[last_end..found_match.start()]);
+        if is_match_selected(count, all, nth, every_nth) {
+            result.push_str(&expand_replacement(replacement, &captures));
+        } else {
+            result.push_str(found_match.as_str());
+        }
+        last_end = found_match.end();
+    }
+
+    result.push_str(&content[last_end..]);
+    result
+}
+
+/// Replaces the selected matches of a pattern in a single line, expanding
+/// capture group references, and advances the shared match `count` so it
+/// stays correct across line boundaries.
+fn replace_matches_in_line(
+    re: &Regex,
+    replacement: &str,
+    line: &str,
+    all: bool,
+    nth: Option<u16>,
+    every_nth: Option<u16>,
+    count: &mut usize,
+) -> String {
+    let mut result = String::with_capacity(line.len());
+    let mut last_end = 0;
+
+    for captures in re.captures_iter(line) {
+        let found_match = captures.get(0).unwrap();
+        *count += 1;
+
+        result.push_str(&line[last_end..found_match.start()]);
+        if is_match_selected(*count, all, nth, every_nth) {
+            result.push_str(&expand_replacement(replacement, &captures));
+        } else {
+            result.push_str(found_match.as_str());
+        }
+        last_end = found_match.end();
+    }
+
+    result.push_str(&line[last_end..]);
+    result
+}
+
+/// Returns the length in bytes of the line terminator (`\r\n`, `\n`, or none
+/// at end of file) at the end of a raw line read via `read_until(b'\n', ..)`,
+/// so it can be preserved byte-for-byte instead of normalized to `\n`.
+fn line_terminator_len(raw_line: &[u8]) -> usize {
+    if raw_line.ends_with(b"\r\n") {
+        2
+    } else if raw_line.ends_with(b"\n") {
+        1
+    } else {
+        0
+    }
+}
+
+/// Replaces the selected matches of a pattern in the file at `path`,
+/// processing it as a streaming line iterator rather than loading the whole
+/// file into memory, while keeping a running global match counter so
+/// `--nth`/`--every_nth` still count correctly across line boundaries. Each
+/// line's original terminator (`\r\n`, `\n`, or none at end of file) is
+/// preserved as-is. Because matching happens per line, a pattern relying on
+/// `--multiline`/`--dot-matches-newline` to match across a line boundary will
+/// not match here the way it would against an in-memory `--haystack`.
+///
+/// When `in_place` is `true`, the result is written to a temporary file next
+/// to `path` and atomically renamed over it on success, so a failure mid-run
+/// cannot corrupt the original file; the return value is `None` in that
+/// case. When `in_place` is `false`, nothing is written and the result is
+/// returned as a `String`.
+///
+/// # Arguments
+///
+/// * `path` - The file to read, and to rewrite when `in_place` is `true`.
+/// * `pattern` - A regex pattern to match.
+/// * `replacement` - The replacement string, which may contain capture group
+///   references (`$1`, `${name}`, `$$`).
+/// * `all` - Replace every match when `true`.
+/// * `nth` - Replace only the 1-based nth match, if given.
+/// * `every_nth` - Replace every 1-based nth match, if given.
+/// * `in_place` - Rewrite `path` in place instead of returning the result.
+/// * `flags` - The inline flags to apply before compilation.
+///
+/// # Returns
+///
+/// `Some(result)` when `in_place` is `false`, `None` when the file was
+/// rewritten in place.
+///
+/// # Panics
+///
+/// This function will panic if the file cannot be read, contains invalid
+/// UTF-8, the temporary file cannot be created or written, or the rename
+/// cannot be completed.
+#[allow(clippy::too_many_arguments)]
+pub fn replace_matches_in_file(
+    path: &std::path::Path,
+    pattern: &str,
+    replacement: &str,
+    all: bool,
+    nth: Option<u16>,
+    every_nth: Option<u16>,
+    in_place: bool,
+    flags: RegexFlags,
+) -> Option<String> {
+    use std::io::{BufRead, Write};
+
+    let re = build_regex(pattern, flags).expect(&format!("Invalid regex pattern: {}", pattern));
+    let file = std::fs::File::open(path).expect("Failed to open input file");
+    let mut reader = std::io::BufReader::new(file);
+    let mut count: usize = 0;
+    let mut raw_line = Vec::new();
+
+    if !in_place {
+        let mut result = String::new();
+        loop {
+            raw_line.clear();
+            let bytes_read = reader
+                .read_until(b'\n', &mut raw_line)
+                .expect("Failed to read line from input file");
+            if bytes_read == 0 {
+                break;
+            }
+
+            let terminator_len = line_terminator_len(&raw_line);
+            let line = std::str::from_utf8(&raw_line[..raw_line.len() - terminator_len])
+                .expect("File contains invalid UTF-8; use --bytes for binary-safe replacement");
+
+            result.push_str(&replace_matches_in_line(
+                &re, replacement, line, all, nth, every_nth, &mut count,
+            ));
+            result
+                .push_str(std::str::from_utf8(&raw_line[raw_line.len() - terminator_len..]).unwrap());
+        }
+        return Some(result);
+    }
+
+    let mut temp_name = path
+        .file_name()
+        .expect("--file path must name a file")
+        .to_os_string();
+    temp_name.push(".replace.tmp");
+    let temp_path = path.with_file_name(temp_name);
+
+    let temp_file = std::fs::File::create(&temp_path).expect("Failed to create temporary file");
+    let mut writer = std::io::BufWriter::new(temp_file);
+
+    loop {
+        raw_line.clear();
+        let bytes_read = reader
+            .read_until(b'\n', &mut raw_line)
+            .expect("Failed to read line from input file");
+        if bytes_read == 0 {
+            break;
+        }
+
+        let terminator_len = line_terminator_len(&raw_line);
+        let line = std::str::from_utf8(&raw_line[..raw_line.len() - terminator_len])
+            .expect("File contains invalid UTF-8; use --bytes for binary-safe replacement");
+
+        let replaced =
+            replace_matches_in_line(&re, replacement, line, all, nth, every_nth, &mut count);
+        writer
+            .write_all(replaced.as_bytes())
+            .expect("Failed to write to temporary file");
+        writer
+            .write_all(&raw_line[raw_line.len() - terminator_len..])
+            .expect("Failed to write to temporary file");
+    }
+
+    writer.flush().expect("Failed to flush temporary file");
+    drop(writer);
+    std::fs::rename(&temp_path, path).expect("Failed to rename temporary file over original");
+
+    None
+}